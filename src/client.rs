@@ -0,0 +1,110 @@
+use crate::http::{Method, Request, Response};
+use crate::Error;
+use std::rc::Rc;
+use url::Url;
+
+/// Cross-cutting behavior (logging, auth injection, retries, ...) that
+/// wraps every request issued by a [`Client`].
+#[async_trait::async_trait(?Send)]
+pub trait Middleware {
+    async fn handle(&self, req: Request, next: Next<'_>) -> Result<Response, Error>;
+}
+
+/// The remaining middleware in a [`Client`]'s chain.
+pub struct Next<'a> {
+    middleware: &'a [Rc<dyn Middleware>],
+}
+
+impl<'a> Next<'a> {
+    pub(crate) fn new(middleware: &'a [Rc<dyn Middleware>]) -> Self {
+        Self { middleware }
+    }
+
+    /// Hands `req` to the next middleware in the chain, or performs the
+    /// actual fetch once the chain is exhausted.
+    pub async fn run(mut self, req: Request) -> Result<Response, Error> {
+        match self.middleware.split_first() {
+            Some((current, rest)) => {
+                self.middleware = rest;
+                current.handle(req, self).await
+            }
+            None => req.send_without_middleware().await,
+        }
+    }
+}
+
+/// A reusable HTTP client: a base url, default headers, and a middleware
+/// chain shared by every request it issues.
+pub struct Client {
+    base_url: Url,
+    headers: Vec<(String, String)>,
+    middleware: Vec<Rc<dyn Middleware>>,
+}
+
+impl Client {
+    /// Creates a new client with the given base url.
+    pub fn new(base_url: &str) -> Self {
+        Self {
+            base_url: Url::parse(base_url).expect("valid base url"),
+            headers: Vec::new(),
+            middleware: Vec::new(),
+        }
+    }
+
+    /// Sets a header sent with every request issued by this client.
+    pub fn header(mut self, key: &str, value: &str) -> Self {
+        self.headers.push((key.to_string(), value.to_string()));
+        self
+    }
+
+    /// Appends a middleware to the end of this client's chain.
+    pub fn middleware(mut self, middleware: impl Middleware + 'static) -> Self {
+        self.middleware.push(Rc::new(middleware));
+        self
+    }
+
+    fn request(&self, method: Method, path: &str) -> Request {
+        let url = self.base_url.join(path).expect("valid url");
+        let mut request = Request::new(url.as_str()).method(method);
+        for (key, value) in &self.headers {
+            request = request.header(key, value);
+        }
+        request.with_middleware(Rc::new(self.middleware.clone()))
+    }
+
+    /// Creates a `GET` request to `path`, pre-seeded with this client's
+    /// base url, default headers, and middleware.
+    pub fn get(&self, path: &str) -> Request {
+        self.request(Method::GET, path)
+    }
+
+    /// Creates a `POST` request to `path`.
+    pub fn post(&self, path: &str) -> Request {
+        self.request(Method::POST, path)
+    }
+
+    /// Creates a `PUT` request to `path`.
+    pub fn put(&self, path: &str) -> Request {
+        self.request(Method::PUT, path)
+    }
+
+    /// Creates a `DELETE` request to `path`.
+    pub fn delete(&self, path: &str) -> Request {
+        self.request(Method::DELETE, path)
+    }
+
+    /// Creates a `PATCH` request to `path`.
+    pub fn patch(&self, path: &str) -> Request {
+        self.request(Method::PATCH, path)
+    }
+
+    /// Creates a `HEAD` request to `path`.
+    pub fn head(&self, path: &str) -> Request {
+        self.request(Method::HEAD, path)
+    }
+
+    /// Creates an `OPTIONS` request to `path`.
+    pub fn options(&self, path: &str) -> Request {
+        self.request(Method::OPTIONS, path)
+    }
+}
@@ -0,0 +1,48 @@
+use std::fmt;
+use wasm_bindgen::{JsCast, JsValue};
+
+/// The error type used throughout this crate.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// The underlying JS value rejected the operation, typically surfaced
+    /// when the `fetch` promise rejects.
+    #[error("js error: {0}")]
+    JsError(JsError),
+    /// A JSON (de)serialization error.
+    #[error(transparent)]
+    SerdeError(#[from] serde_json::Error),
+    /// A (de)serialization error from the `serde-wasm-bindgen` backend.
+    #[cfg(feature = "serde-wasm-bindgen")]
+    #[error(transparent)]
+    SerdeWasmBindgenError(#[from] serde_wasm_bindgen::Error),
+    /// Any other error.
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+    /// The request was aborted because it did not complete within the
+    /// duration passed to [`Request::timeout`](crate::http::Request::timeout).
+    #[error("request timed out")]
+    Timeout,
+}
+
+/// A JS error value, preserving its `name` and `message`.
+#[derive(Debug)]
+pub struct JsError {
+    pub name: String,
+    pub message: String,
+}
+
+impl fmt::Display for JsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.name, self.message)
+    }
+}
+
+pub(crate) fn js_to_error(js_value: JsValue) -> Error {
+    match js_value.dyn_into::<js_sys::Error>() {
+        Ok(error) => Error::JsError(JsError {
+            name: String::from(error.name()),
+            message: String::from(error.message()),
+        }),
+        Err(js_value) => Error::Other(anyhow::anyhow!("unexpected error value: {:?}", js_value)),
+    }
+}
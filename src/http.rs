@@ -1,6 +1,11 @@
 use crate::{js_to_error, Error};
+use gloo_timers::callback::Timeout;
 use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::cell::Cell;
 use std::fmt;
+use std::rc::Rc;
+use url::form_urlencoded;
 use wasm_bindgen::prelude::*;
 use wasm_bindgen::JsCast;
 use wasm_bindgen_futures::JsFuture;
@@ -11,13 +16,19 @@ pub use web_sys::{
 };
 
 /// Valid request methods.
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Debug)]
 pub enum Method {
     GET,
     POST,
     PATCH,
     DELETE,
     PUT,
+    HEAD,
+    OPTIONS,
+    CONNECT,
+    TRACE,
+    /// A method outside the Fetch spec's fixed set, e.g. WebDAV's `PROPFIND`.
+    Other(String),
 }
 
 impl fmt::Display for Method {
@@ -28,16 +39,80 @@ impl fmt::Display for Method {
             Method::PATCH => "PATCH",
             Method::DELETE => "DELETE",
             Method::PUT => "PUT",
+            Method::HEAD => "HEAD",
+            Method::OPTIONS => "OPTIONS",
+            Method::CONNECT => "CONNECT",
+            Method::TRACE => "TRACE",
+            Method::Other(method) => method.as_str(),
         };
         write!(f, "{}", s)
     }
 }
 
+impl std::str::FromStr for Method {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.to_ascii_uppercase().as_str() {
+            "GET" => Method::GET,
+            "POST" => Method::POST,
+            "PATCH" => Method::PATCH,
+            "DELETE" => Method::DELETE,
+            "PUT" => Method::PUT,
+            "HEAD" => Method::HEAD,
+            "OPTIONS" => Method::OPTIONS,
+            "CONNECT" => Method::CONNECT,
+            "TRACE" => Method::TRACE,
+            _ => Method::Other(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::TryFrom<&str> for Method {
+    type Error = std::convert::Infallible;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+/// Splits a url into its base, query (without the leading `?`), and
+/// fragment (without the leading `#`) parts, without requiring the url to
+/// be absolute.
+fn split_url(url: &str) -> (&str, Option<&str>, Option<&str>) {
+    let (rest, fragment) = match url.find('#') {
+        Some(i) => (&url[..i], Some(&url[i + 1..])),
+        None => (url, None),
+    };
+    let (base, query) = match rest.find('?') {
+        Some(i) => (&rest[..i], Some(&rest[i + 1..])),
+        None => (rest, None),
+    };
+    (base, query, fragment)
+}
+
+/// The inverse of [`split_url`], omitting the query entirely when empty.
+fn join_url(base: &str, query: &str, fragment: Option<&str>) -> String {
+    let mut url = base.to_string();
+    if !query.is_empty() {
+        url.push('?');
+        url.push_str(query);
+    }
+    if let Some(fragment) = fragment {
+        url.push('#');
+        url.push_str(fragment);
+    }
+    url
+}
+
 /// A request.
 pub struct Request {
     options: web_sys::RequestInit,
     headers: web_sys::Headers,
     url: String,
+    abort_signal: Option<AbortSignal>,
+    timeout: Option<u32>,
+    middleware: Option<Rc<Vec<Rc<dyn crate::client::Middleware>>>>,
 }
 
 impl Request {
@@ -47,15 +122,48 @@ impl Request {
             options: web_sys::RequestInit::new(),
             headers: web_sys::Headers::new().expect("headers"),
             url: url.into(),
+            abort_signal: None,
+            timeout: None,
+            middleware: None,
         }
     }
 
+    /// Attaches a client's middleware chain, so `send` runs it before
+    /// performing the fetch.
+    pub(crate) fn with_middleware(
+        mut self,
+        middleware: Rc<Vec<Rc<dyn crate::client::Middleware>>>,
+    ) -> Self {
+        self.middleware = Some(middleware);
+        self
+    }
+
     /// Sets the body.
     pub fn body(mut self, body: impl Into<JsValue>) -> Self {
         self.options.body(Some(&body.into()));
         self
     }
 
+    /// Sets the body by serializing `value` as JSON, and sets the
+    /// `content-type` header to `application/json` unless one was already
+    /// set.
+    pub fn json<T: Serialize>(mut self, value: &T) -> Result<Self, Error> {
+        #[cfg(feature = "serde-wasm-bindgen")]
+        let body = js_sys::JSON::stringify(&serde_wasm_bindgen::to_value(value)?)
+            .map_err(js_to_error)?
+            .into();
+        #[cfg(not(feature = "serde-wasm-bindgen"))]
+        let body = JsValue::from_str(&serde_json::to_string(value)?);
+
+        self.options.body(Some(&body));
+        if self.headers.get("content-type").map_err(js_to_error)?.is_none() {
+            self.headers
+                .set("content-type", "application/json")
+                .map_err(js_to_error)?;
+        }
+        Ok(self)
+    }
+
     /// Sets the request cache.
     pub fn cache(mut self, cache: RequestCache) -> Self {
         self.options.cache(cache);
@@ -86,6 +194,40 @@ impl Request {
         self
     }
 
+    /// Sets the request's query parameters, replacing any that are already
+    /// present in the url.
+    pub fn query<I, K, V>(mut self, params: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: AsRef<str>,
+        V: AsRef<str>,
+    {
+        let (base, _, fragment) = split_url(&self.url);
+        let query = form_urlencoded::Serializer::new(String::new())
+            .extend_pairs(params)
+            .finish();
+        self.url = join_url(base, &query, fragment);
+        self
+    }
+
+    /// Appends additional query parameters to the url, keeping any that are
+    /// already present.
+    pub fn query_pairs<I, K, V>(mut self, params: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: AsRef<str>,
+        V: AsRef<str>,
+    {
+        let (base, existing, fragment) = split_url(&self.url);
+        let mut serializer = form_urlencoded::Serializer::new(String::new());
+        if let Some(existing) = existing {
+            serializer.extend_pairs(form_urlencoded::parse(existing.as_bytes()));
+        }
+        let query = serializer.extend_pairs(params).finish();
+        self.url = join_url(base, &query, fragment);
+        self
+    }
+
     /// Sets the request mode.
     pub fn mode(mut self, mode: RequestMode) -> Self {
         self.options.mode(mode);
@@ -118,19 +260,93 @@ impl Request {
 
     /// Sets the request abort signal.
     pub fn abort_signal(mut self, signal: Option<&AbortSignal>) -> Self {
-        self.options.signal(signal);
+        self.abort_signal = signal.cloned();
+        self
+    }
+
+    /// Aborts the request if it has not completed within `millis`
+    /// milliseconds, failing it with [`Error::Timeout`].
+    pub fn timeout(mut self, millis: u32) -> Self {
+        self.timeout = Some(millis);
         self
     }
 
-    /// Executes the request.
+    /// Executes the request, running it through the issuing client's
+    /// middleware chain first, if any.
     pub async fn send(mut self) -> Result<Response, Error> {
+        match self.middleware.take() {
+            Some(middleware) => crate::client::Next::new(&middleware).run(self).await,
+            None => self.send_without_middleware().await,
+        }
+    }
+
+    /// Performs the actual fetch, bypassing any middleware chain. This is
+    /// what [`Next::run`][crate::client::Next::run] calls once the chain is
+    /// exhausted.
+    pub(crate) async fn send_without_middleware(mut self) -> Result<Response, Error> {
+        let controller = match self.timeout {
+            Some(_) => Some(web_sys::AbortController::new().map_err(js_to_error)?),
+            None => None,
+        };
+
+        let signal = match (&controller, &self.abort_signal) {
+            (Some(controller), _) => Some(controller.signal()),
+            (None, signal) => signal.clone(),
+        };
+        self.options.signal(signal.as_ref());
+
+        // If the caller supplied their own signal alongside a timeout, forward
+        // its abort into our controller so either one can cancel the request.
+        let forward_abort = match (&controller, &self.abort_signal) {
+            (Some(controller), Some(user_signal)) => {
+                let controller = controller.clone();
+                let closure = Closure::wrap(Box::new(move || controller.abort()) as Box<dyn FnMut()>);
+                user_signal
+                    .add_event_listener_with_callback("abort", closure.as_ref().unchecked_ref())
+                    .map_err(js_to_error)?;
+                Some((user_signal.clone(), closure))
+            }
+            _ => None,
+        };
+
         self.options.headers(&self.headers);
 
         let request = web_sys::Request::new_with_str_and_init(&self.url, &self.options)
             .map_err(js_to_error)?;
 
         let promise = window().unwrap().fetch_with_request(&request);
-        let response = JsFuture::from(promise).await.map_err(js_to_error)?;
+
+        let timed_out = Rc::new(Cell::new(false));
+
+        let timer = match (&controller, self.timeout) {
+            (Some(controller), Some(millis)) => {
+                let controller = controller.clone();
+                let timed_out = timed_out.clone();
+                Some(Timeout::new(millis, move || {
+                    timed_out.set(true);
+                    controller.abort();
+                }))
+            }
+            _ => None,
+        };
+
+        let result = JsFuture::from(promise).await;
+        drop(timer);
+
+        if let Some((user_signal, closure)) = forward_abort {
+            user_signal
+                .remove_event_listener_with_callback("abort", closure.as_ref().unchecked_ref())
+                .map_err(js_to_error)?;
+        }
+
+        let response = result.map_err(|err| {
+            if timed_out.get() {
+                Error::Timeout
+            } else {
+                js_to_error(err)
+            }
+        })?;
+
         match response.dyn_into::<web_sys::Response>() {
             Ok(response) => Ok(Response {
                 response: response.unchecked_into(),
@@ -159,6 +375,16 @@ impl Request {
         Self::new(url).method(Method::DELETE)
     }
 
+    /// Creates a new [`HEAD`][Method::HEAD] `Request` with url.
+    pub fn head(url: &str) -> Self {
+        Self::new(url).method(Method::HEAD)
+    }
+
+    /// Creates a new [`OPTIONS`][Method::OPTIONS] `Request` with url.
+    pub fn options(url: &str) -> Self {
+        Self::new(url).method(Method::OPTIONS)
+    }
+
     /// Creates a new [`PATCH`][RequestMethod::PATCH] `Request` with url.
     pub fn patch(url: &str) -> Self {
         Self::new(url).method(Method::PATCH)
@@ -216,6 +442,13 @@ impl Response {
         &self.response
     }
 
+    /// Gets the response body as raw bytes.
+    pub async fn binary(&self) -> Result<Vec<u8>, Error> {
+        let promise = self.response.array_buffer().map_err(js_to_error)?;
+        let buffer = JsFuture::from(promise).await.map_err(js_to_error)?;
+        Ok(js_sys::Uint8Array::new(&buffer).to_vec())
+    }
+
     /// Gets the form data.
     pub async fn form_data(&self) -> Result<FormData, Error> {
         let promise = self.response.form_data().map_err(js_to_error)?;
@@ -228,6 +461,9 @@ impl Response {
         let promise = self.response.json().map_err(js_to_error)?;
         let json = JsFuture::from(promise).await.map_err(js_to_error)?;
 
+        #[cfg(feature = "serde-wasm-bindgen")]
+        return Ok(serde_wasm_bindgen::from_value(json)?);
+        #[cfg(not(feature = "serde-wasm-bindgen"))]
         Ok(json.into_serde()?)
     }
 
@@ -0,0 +1,8 @@
+//! An ergonomic wrapper around `web_sys`'s `fetch` API.
+pub mod client;
+mod error;
+pub mod http;
+
+pub use client::{Client, Middleware, Next};
+pub use error::{Error, JsError};
+pub(crate) use error::js_to_error;